@@ -6,13 +6,17 @@ declare_id!("FfjNyygvYw56Qaq1MUj34U3nMb3uVb5NjCUjjRzMashR");
 pub mod tracking_system {
     use super::*;
 
-    // Initialize the tracker registry (any user can do this)
+    // Initialize the tracker registry (any user can do this). The caller
+    // becomes the registry authority responsible for moderation.
     pub fn initialize(
         ctx: Context<Initialize>,
     ) -> Result<()> {
         // Initialize the registry with an empty vector of tracker names
-        ctx.accounts.tracker_registry.set_inner(TrackerRegistry::default());
-        
+        let mut registry = TrackerRegistry::default();
+        registry.authority = ctx.accounts.user.key();
+        registry.bump = ctx.bumps.tracker_registry;
+        ctx.accounts.tracker_registry.set_inner(registry);
+
         Ok(())
     }
 
@@ -22,19 +26,39 @@ pub mod tracking_system {
         title: String,
         description: String,
     ) -> Result<()> {
+        // Hand out the next tracker id from the registry's counter instead of
+        // a byte of the tracker's own PDA, which only had 256 possible values
+        // and let unrelated trackers collide on id (and therefore on every
+        // PDA seeded by it).
+        let tracker_id = ctx.accounts.tracker_registry.next_tracker_id;
+        ctx.accounts.tracker_registry.next_tracker_id = tracker_id
+            .checked_add(1)
+            .ok_or(TrackingError::TrackerIdOverflow)?;
+
         // Create a new tracker with the provided title and description
         let tracker = Tracker {
-            id: ctx.accounts.tracker.key().to_bytes()[0] as u32, // Use first byte of PDA as ID
+            id: tracker_id,
             title: title.clone(),
             description,
+            is_archived: false,
+            bump: ctx.bumps.tracker,
         };
-        
+
         // Store the tracker in the PDA
         ctx.accounts.tracker.set_inner(tracker.clone());
-        
+
         // Add the tracker name to the registry
         ctx.accounts.tracker_registry.tracker_names.push(title);
-        
+
+        // Grow the registry to fit the new name before it gets serialized
+        let new_len = packed_len(&*ctx.accounts.tracker_registry)?;
+        realloc_account_for_growth(
+            &ctx.accounts.tracker_registry.to_account_info(),
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_len,
+        )?;
+
         Ok(())
     }
 
@@ -45,11 +69,18 @@ pub mod tracking_system {
         count: u32,
         date: u64,
     ) -> Result<()> {
-        // Verify the tracker exists and matches the ID
+        // Registry-wide emergency pause takes priority over everything else
+        require!(
+            !ctx.accounts.tracker_registry.is_paused,
+            TrackingError::RegistryPaused
+        );
+
+        // Verify the tracker exists, matches the ID, and hasn't been archived
         require!(
             ctx.accounts.tracker.id == tracker_id,
             TrackingError::InvalidTrackerId
         );
+        require!(!ctx.accounts.tracker.is_archived, TrackingError::TrackerArchived);
 
         let tracking_data = &mut ctx.accounts.tracking_data;
         let is_new_user = tracking_data.user == Pubkey::default();
@@ -58,6 +89,7 @@ pub mod tracking_system {
         if is_new_user {
             tracking_data.user = ctx.accounts.user.key();
             tracking_data.tracker_id = tracker_id;
+            tracking_data.bump = ctx.bumps.tracking_data;
         }
         
         // Normalize date to midnight GMT (00:00:00)
@@ -78,6 +110,15 @@ pub mod tracking_system {
             tracking_data.tracks.push(track);
             // Sort tracks by date in descending order
             tracking_data.tracks.sort_by(|a, b| b.date.cmp(&a.date));
+
+            // Grow the account to fit the new track before it gets serialized
+            let new_len = packed_len(&**tracking_data)?;
+            realloc_account_for_growth(
+                &tracking_data.to_account_info(),
+                &ctx.accounts.user.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                new_len,
+            )?;
         }
 
         // Update tracker stats for today
@@ -87,6 +128,7 @@ pub mod tracking_system {
         // Initialize tracker_stats_list if needed
         if tracker_stats_list.tracker_id == 0 {
             tracker_stats_list.tracker_id = tracker_id;
+            tracker_stats_list.bump = ctx.bumps.tracker_stats_list;
         }
 
         if tracker_stats.tracker_id == 0 && tracker_stats.date == 0 {
@@ -95,7 +137,8 @@ pub mod tracking_system {
             tracker_stats.date = normalized_date;
             tracker_stats.total_count = count;
             tracker_stats.unique_users = 1;
-            
+            tracker_stats.bump = ctx.bumps.tracker_stats;
+
             // Add the date to the list if it's not already there
             if !tracker_stats_list.stats.iter().any(|s| s.date == normalized_date) {
                 tracker_stats_list.stats.push(TrackerStatsAccount {
@@ -103,7 +146,16 @@ pub mod tracking_system {
                     date: normalized_date,
                     total_count: count,
                     unique_users: 1,
+                    bump: tracker_stats.bump,
                 });
+
+                let new_len = packed_len(&**tracker_stats_list)?;
+                realloc_account_for_growth(
+                    &tracker_stats_list.to_account_info(),
+                    &ctx.accounts.user.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    new_len,
+                )?;
             }
         } else {
             // Update existing account
@@ -136,50 +188,177 @@ pub mod tracking_system {
                     date: normalized_date,
                     total_count: tracker_stats.total_count,
                     unique_users: tracker_stats.unique_users,
+                    bump: tracker_stats.bump,
                 });
+
+                let new_len = packed_len(&**tracker_stats_list)?;
+                realloc_account_for_growth(
+                    &tracker_stats_list.to_account_info(),
+                    &ctx.accounts.user.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    new_len,
+                )?;
             }
         }
 
-        // Update streak information
-        let tracker_streak = &mut ctx.accounts.tracker_streak;
-        let one_day = 86400; // 24 hours in seconds
-
-        if tracker_streak.user == Pubkey::default() {
-            // Initialize streak account for new user
-            tracker_streak.user = ctx.accounts.user.key();
-            tracker_streak.tracker_id = tracker_id;
-            tracker_streak.streak = 1;
-            tracker_streak.last_streak_date = normalized_date;
-            tracker_streak.longest_streak = 1;
-            tracker_streak.longest_streak_date = normalized_date;
-        } else {
-            // Update existing streak
-            let last_date = tracker_streak.last_streak_date;
-            
-            if normalized_date == last_date {
-                // Same day, no streak change
-                return Ok(());
+        // Recompute streak information from the full, sorted track history
+        // rather than incrementally, so out-of-order and backfilled dates
+        // (see `backfill_tracking_data`) always leave a correct streak.
+        sync_streak_and_leaderboard(
+            &mut ctx.accounts.tracker_streak,
+            &mut ctx.accounts.tracker_leaderboard,
+            tracker_stats_list,
+            ctx.accounts.user.key(),
+            tracker_id,
+            ctx.bumps.tracker_streak,
+            ctx.bumps.tracker_leaderboard,
+            &tracking_data.tracks,
+            normalized_date,
+        );
+
+        Ok(())
+    }
+
+    // Insert or correct a (possibly past) date's entry for a user, then
+    // fully recompute the streak from history. Unlike `add_tracking_data`,
+    // an existing entry for the date is updated in place instead of
+    // erroring, so this covers both out-of-order submissions and
+    // backfilling missed days.
+    pub fn backfill_tracking_data(
+        ctx: Context<BackfillTrackingData>,
+        tracker_id: u32,
+        count: u32,
+        date: u64,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.tracker_registry.is_paused,
+            TrackingError::RegistryPaused
+        );
+        require!(
+            ctx.accounts.tracker.id == tracker_id,
+            TrackingError::InvalidTrackerId
+        );
+        require!(!ctx.accounts.tracker.is_archived, TrackingError::TrackerArchived);
+
+        let normalized_date = (date / 86400) * 86400;
+        let tracking_data = &mut ctx.accounts.tracking_data;
+
+        let old_count = match tracking_data.tracks.iter_mut().find(|t| t.date == normalized_date) {
+            Some(existing) => {
+                let old_count = existing.count;
+                existing.count = count;
+                Some(old_count)
+            }
+            None => {
+                tracking_data.tracks.push(Track {
+                    date: normalized_date,
+                    count,
+                });
+                tracking_data.tracks.sort_by(|a, b| b.date.cmp(&a.date));
+
+                let new_len = packed_len(&**tracking_data)?;
+                realloc_account_for_growth(
+                    &tracking_data.to_account_info(),
+                    &ctx.accounts.user.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    new_len,
+                )?;
+                None
+            }
+        };
+
+        // Keep the per-date tracker stats in sync with the corrected count
+        let tracker_stats = &mut ctx.accounts.tracker_stats;
+        let tracker_stats_list = &mut ctx.accounts.tracker_stats_list;
+
+        if tracker_stats_list.tracker_id == 0 {
+            tracker_stats_list.tracker_id = tracker_id;
+            tracker_stats_list.bump = ctx.bumps.tracker_stats_list;
+        }
+
+        if tracker_stats.tracker_id == 0 && tracker_stats.date == 0 {
+            tracker_stats.tracker_id = tracker_id;
+            tracker_stats.date = normalized_date;
+            tracker_stats.total_count = count;
+            tracker_stats.unique_users = 1;
+            tracker_stats.bump = ctx.bumps.tracker_stats;
+        } else if tracker_stats.date == normalized_date {
+            match old_count {
+                Some(old) => tracker_stats.total_count = tracker_stats.total_count - old + count,
+                None => {
+                    tracker_stats.total_count += count;
+                    tracker_stats.unique_users += 1;
+                }
             }
+        }
 
-            if normalized_date == last_date + one_day  && count > 0{
-                // Next day, increment streak
-                tracker_streak.streak += 1;
-                if tracker_streak.streak > tracker_streak.longest_streak {
-                    tracker_streak.longest_streak = tracker_streak.streak;
-                    tracker_streak.longest_streak_date = normalized_date;
+        if let Some(stats_entry) = tracker_stats_list.stats.iter_mut().find(|s| s.date == normalized_date) {
+            match old_count {
+                Some(old) => stats_entry.total_count = stats_entry.total_count - old + count,
+                None => {
+                    stats_entry.total_count += count;
+                    stats_entry.unique_users += 1;
                 }
-            } else if normalized_date > last_date + one_day {
-                tracker_streak.streak = 1;
-            } else if count == 0 {
-               tracker_streak.streak = 0;
             }
-            
-            tracker_streak.last_streak_date = normalized_date;
+        } else {
+            tracker_stats_list.stats.push(TrackerStatsAccount {
+                tracker_id,
+                date: normalized_date,
+                total_count: count,
+                unique_users: 1,
+                bump: tracker_stats.bump,
+            });
+
+            let new_len = packed_len(&**tracker_stats_list)?;
+            realloc_account_for_growth(
+                &tracker_stats_list.to_account_info(),
+                &ctx.accounts.user.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                new_len,
+            )?;
         }
 
+        // Full recompute, same as `add_tracking_data`, so the backfilled
+        // date is reflected correctly regardless of where it lands.
+        // tracker_streak/tracker_leaderboard are constrained with an
+        // explicit `bump = ...` here (they must already exist to be
+        // backfilled), so there's no `ctx.bumps` entry for them — read
+        // the bump already stored on each account instead.
+        let streak_bump = ctx.accounts.tracker_streak.bump;
+        let leaderboard_bump = ctx.accounts.tracker_leaderboard.bump;
+        sync_streak_and_leaderboard(
+            &mut ctx.accounts.tracker_streak,
+            &mut ctx.accounts.tracker_leaderboard,
+            tracker_stats_list,
+            ctx.accounts.user.key(),
+            tracker_id,
+            streak_bump,
+            leaderboard_bump,
+            &tracking_data.tracks,
+            normalized_date,
+        );
+
         Ok(())
     }
 
+    // View function to get the top-N leaderboard for a tracker in the given mode
+    pub fn get_leaderboard(
+        ctx: Context<GetLeaderboard>,
+        tracker_id: u32,
+        mode: LeaderboardMode,
+    ) -> Result<Vec<LeaderboardEntry>> {
+        require!(
+            ctx.accounts.tracker_leaderboard.tracker_id == tracker_id,
+            TrackingError::InvalidTrackerId
+        );
+
+        let leaderboard = &ctx.accounts.tracker_leaderboard;
+        Ok(match mode {
+            LeaderboardMode::LongestStreak => leaderboard.longest_streak_board.clone(),
+            LeaderboardMode::TotalCount => leaderboard.total_count_board.clone(),
+        })
+    }
+
     // View function to get all tracker names
     pub fn get_all_trackers(ctx: Context<GetAllTrackers>) -> Result<Vec<String>> {
         Ok(ctx.accounts.tracker_registry.tracker_names.clone())
@@ -249,6 +428,8 @@ pub mod tracking_system {
             last_streak_date: ctx.accounts.tracker_streak.last_streak_date,
             longest_streak: ctx.accounts.tracker_streak.longest_streak,
             longest_streak_date: ctx.accounts.tracker_streak.longest_streak_date,
+            total_count: ctx.accounts.tracker_streak.total_count,
+            bump: ctx.accounts.tracker_streak.bump,
         };
         Ok(streak_account)
     }
@@ -265,6 +446,47 @@ pub mod tracking_system {
 
         Ok(ctx.accounts.tracker_stats_list.stats.clone())
     }
+
+    // Authority-only: archive a tracker so it stops accepting new tracking data
+    pub fn archive_tracker(ctx: Context<ArchiveTracker>, tracker_id: u32) -> Result<()> {
+        require!(
+            ctx.accounts.tracker.id == tracker_id,
+            TrackingError::InvalidTrackerId
+        );
+
+        ctx.accounts.tracker.is_archived = true;
+        Ok(())
+    }
+
+    // Authority-only: set the registry-wide emergency pause flag to `is_paused`
+    pub fn set_registry_paused(ctx: Context<SetRegistryPaused>, is_paused: bool) -> Result<()> {
+        ctx.accounts.tracker_registry.is_paused = is_paused;
+        Ok(())
+    }
+
+    // View function returning a point-in-time aggregate snapshot of the
+    // registry, computed from the `TrackerStatsList` accounts passed in
+    // `remaining_accounts`, so operators get dashboard health in one call.
+    pub fn get_registry_snapshot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetRegistrySnapshot<'info>>,
+    ) -> Result<RegistrySnapshot> {
+        let tracker_count = ctx.accounts.tracker_registry.tracker_names.len() as u32;
+
+        let mut total_unique_users: u32 = 0;
+        let mut total_events: u32 = 0;
+
+        for stats_list_info in ctx.remaining_accounts.iter() {
+            let stats_list: Account<TrackerStatsList> = Account::try_from(stats_list_info)?;
+            total_unique_users += stats_list.total_unique_users;
+            total_events += stats_list.stats.iter().map(|s| s.total_count).sum::<u32>();
+        }
+
+        Ok(RegistrySnapshot {
+            tracker_count,
+            total_unique_users,
+            total_events,
+        })
+    }
 }
 
 #[derive(Accounts)]
@@ -300,14 +522,14 @@ pub struct CreateTracker<'info> {
     #[account(
         mut,
         seeds = [b"tracker_registry"],
-        bump
+        bump = tracker_registry.bump
     )]
     pub tracker_registry: Account<'info, TrackerRegistry>,
-    
+
     /// CHECK: This is the user who is creating the tracker
     #[account(mut)]
     pub user: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
 }
 
@@ -318,31 +540,31 @@ pub struct AddTrackingData<'info> {
         init_if_needed,
         payer = user,
         space = 8 + TrackingData::LEN,
-        seeds = [b"tracking_data", user.key().as_ref(), &[tracker_id as u8; 13]],
+        seeds = [b"tracking_data", user.key().as_ref(), &tracker_id.to_le_bytes()],
         bump
     )]
     pub tracking_data: Account<'info, TrackingData>,
-    
+
     #[account(
         seeds = [b"tracker", tracker.title.as_bytes()],
-        bump
+        bump = tracker.bump
     )]
     pub tracker: Account<'info, Tracker>,
-    
+
     /// CHECK: This is the user who is adding tracking data
     #[account(mut)]
     pub user: Signer<'info>,
-    
+
     pub system_program: Program<'info, System>,
-    
+
     #[account(
         init_if_needed,
         payer = user,
         space = 8 + TrackerStatsAccount::LEN,
         seeds = [
             b"tracker_stats",
-            &[tracker_id as u8; 13],
-            &[((date / 86400) * 86400) as u8; 13],
+            tracker_id.to_le_bytes().as_ref(),
+            ((date / 86400) * 86400).to_le_bytes().as_ref(),
         ],
         bump
     )]
@@ -352,7 +574,7 @@ pub struct AddTrackingData<'info> {
         init_if_needed,
         payer = user,
         space = 8 + TrackerStatsList::LEN,
-        seeds = [b"tracker_stats_list", &[tracker_id as u8; 18]],
+        seeds = [b"tracker_stats_list", tracker_id.to_le_bytes().as_ref()],
         bump
     )]
     pub tracker_stats_list: Account<'info, TrackerStatsList>,
@@ -361,17 +583,97 @@ pub struct AddTrackingData<'info> {
         init_if_needed,
         payer = user,
         space = 8 + TrackerStreakAccount::LEN,
-        seeds = [b"tracker_streak", user.key().as_ref(), &[tracker_id as u8; 13]],
+        seeds = [b"tracker_streak", user.key().as_ref(), &tracker_id.to_le_bytes()],
+        bump
+    )]
+    pub tracker_streak: Account<'info, TrackerStreakAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + TrackerLeaderboard::LEN,
+        seeds = [b"tracker_leaderboard", tracker_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub tracker_leaderboard: Account<'info, TrackerLeaderboard>,
+
+    #[account(
+        seeds = [b"tracker_registry"],
+        bump = tracker_registry.bump
+    )]
+    pub tracker_registry: Account<'info, TrackerRegistry>,
+}
+
+#[derive(Accounts)]
+#[instruction(tracker_id: u32, count: u32, date: u64)]
+pub struct BackfillTrackingData<'info> {
+    #[account(
+        mut,
+        seeds = [b"tracking_data", user.key().as_ref(), &tracker_id.to_le_bytes()],
+        bump = tracking_data.bump
+    )]
+    pub tracking_data: Account<'info, TrackingData>,
+
+    #[account(
+        seeds = [b"tracker", tracker.title.as_bytes()],
+        bump = tracker.bump
+    )]
+    pub tracker: Account<'info, Tracker>,
+
+    /// CHECK: This is the user whose tracking data is being backfilled
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + TrackerStatsAccount::LEN,
+        seeds = [
+            b"tracker_stats",
+            tracker_id.to_le_bytes().as_ref(),
+            ((date / 86400) * 86400).to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub tracker_stats: Account<'info, TrackerStatsAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + TrackerStatsList::LEN,
+        seeds = [b"tracker_stats_list", tracker_id.to_le_bytes().as_ref()],
         bump
     )]
+    pub tracker_stats_list: Account<'info, TrackerStatsList>,
+
+    #[account(
+        mut,
+        seeds = [b"tracker_streak", user.key().as_ref(), &tracker_id.to_le_bytes()],
+        bump = tracker_streak.bump
+    )]
     pub tracker_streak: Account<'info, TrackerStreakAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"tracker_leaderboard", tracker_id.to_le_bytes().as_ref()],
+        bump = tracker_leaderboard.bump
+    )]
+    pub tracker_leaderboard: Account<'info, TrackerLeaderboard>,
+
+    #[account(
+        seeds = [b"tracker_registry"],
+        bump = tracker_registry.bump
+    )]
+    pub tracker_registry: Account<'info, TrackerRegistry>,
 }
 
 #[derive(Accounts)]
 pub struct GetAllTrackers<'info> {
     #[account(
         seeds = [b"tracker_registry"],
-        bump
+        bump = tracker_registry.bump
     )]
     pub tracker_registry: Account<'info, TrackerRegistry>,
 }
@@ -380,8 +682,8 @@ pub struct GetAllTrackers<'info> {
 #[instruction(tracker_id: u32)]
 pub struct GetUserTrackingData<'info> {
     #[account(
-        seeds = [b"tracking_data", user.key().as_ref(), &[tracker_id as u8; 13]],
-        bump
+        seeds = [b"tracking_data", user.key().as_ref(), &tracker_id.to_le_bytes()],
+        bump = tracking_data.bump
     )]
     pub tracking_data: Account<'info, TrackingData>,
     /// CHECK: This is the user whose tracking data we're retrieving
@@ -394,15 +696,15 @@ pub struct GetTrackerStats<'info> {
     #[account(
         seeds = [
             b"tracker_stats",
-            &[tracker_id as u8; 13],
-            &[((date / 86400) * 86400) as u8; 13],
+            tracker_id.to_le_bytes().as_ref(),
+            ((date / 86400) * 86400).to_le_bytes().as_ref(),
         ],
-        bump
+        bump = tracker_stats.bump
     )]
     pub tracker_stats: Account<'info, TrackerStatsAccount>,
     #[account(
         seeds = [b"tracker", tracker.title.as_bytes()],
-        bump
+        bump = tracker.bump
     )]
     pub tracker: Account<'info, Tracker>,
 }
@@ -411,8 +713,8 @@ pub struct GetTrackerStats<'info> {
 #[instruction(tracker_id: u32)]
 pub struct GetUserStreak<'info> {
     #[account(
-        seeds = [b"tracker_streak", user.key().as_ref(), &[tracker_id as u8; 13]],
-        bump
+        seeds = [b"tracker_streak", user.key().as_ref(), &tracker_id.to_le_bytes()],
+        bump = tracker_streak.bump
     )]
     pub tracker_streak: Account<'info, TrackerStreakAccount>,
     /// CHECK: This is the user whose streak we're retrieving
@@ -423,17 +725,70 @@ pub struct GetUserStreak<'info> {
 #[instruction(tracker_id: u32)]
 pub struct GetAllTrackerStats<'info> {
     #[account(
-        seeds = [b"tracker_stats_list", &[tracker_id as u8; 18]],
-        bump
+        seeds = [b"tracker_stats_list", tracker_id.to_le_bytes().as_ref()],
+        bump = tracker_stats_list.bump
     )]
     pub tracker_stats_list: Account<'info, TrackerStatsList>,
 }
 
+#[derive(Accounts)]
+#[instruction(tracker_id: u32)]
+pub struct GetLeaderboard<'info> {
+    #[account(
+        seeds = [b"tracker_leaderboard", tracker_id.to_le_bytes().as_ref()],
+        bump = tracker_leaderboard.bump
+    )]
+    pub tracker_leaderboard: Account<'info, TrackerLeaderboard>,
+}
+
+#[derive(Accounts)]
+#[instruction(tracker_id: u32)]
+pub struct ArchiveTracker<'info> {
+    #[account(
+        mut,
+        seeds = [b"tracker", tracker.title.as_bytes()],
+        bump = tracker.bump
+    )]
+    pub tracker: Account<'info, Tracker>,
+
+    #[account(
+        seeds = [b"tracker_registry"],
+        bump = tracker_registry.bump,
+        has_one = authority
+    )]
+    pub tracker_registry: Account<'info, TrackerRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetRegistryPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"tracker_registry"],
+        bump = tracker_registry.bump,
+        has_one = authority
+    )]
+    pub tracker_registry: Account<'info, TrackerRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GetRegistrySnapshot<'info> {
+    #[account(
+        seeds = [b"tracker_registry"],
+        bump = tracker_registry.bump
+    )]
+    pub tracker_registry: Account<'info, TrackerRegistry>,
+}
+
 #[account]
 pub struct TrackingData {
     pub user: Pubkey,
     pub tracker_id: u32,
     pub tracks: Vec<Track>,
+    pub bump: u8,
 }
 
 #[account]
@@ -441,24 +796,50 @@ pub struct Tracker {
     pub id: u32,
     pub title: String,
     pub description: String,
+    pub is_archived: bool,
+    pub bump: u8,
 }
 
 #[account]
 pub struct TrackerRegistry {
     pub tracker_names: Vec<String>,
+    pub authority: Pubkey,
+    pub is_paused: bool,
+    // Monotonically incremented to hand out tracker ids; using the full
+    // counter (instead of a byte of the tracker's own PDA) keeps ids unique
+    // across the lifetime of the registry rather than wrapping after 256.
+    pub next_tracker_id: u32,
+    pub bump: u8,
 }
 
 impl Default for TrackerRegistry {
     fn default() -> Self {
         Self {
             tracker_names: Vec::new(),
+            authority: Pubkey::default(),
+            is_paused: false,
+            next_tracker_id: 0,
+            bump: 0,
         }
     }
 }
 
 impl TrackerRegistry {
+    // Starts empty; `realloc_account_for_growth` grows it by the exact
+    // amount needed as tracker names are pushed, so no fixed cap here.
     pub const LEN: usize = 4 + // tracker_names vector length
-        (4 + 32) * 100; // space for 100 tracker names initially (each name max 32 chars)
+        32 + // authority
+        1 + // is_paused
+        4 + // next_tracker_id
+        1; // bump
+}
+
+// Aggregate, point-in-time metrics for the whole registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegistrySnapshot {
+    pub tracker_count: u32,
+    pub total_unique_users: u32,
+    pub total_events: u32,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -479,6 +860,7 @@ pub struct TrackerStatsAccount {
     pub date: u64,
     pub total_count: u32,
     pub unique_users: u32,
+    pub bump: u8,
 }
 
 #[account]
@@ -489,19 +871,124 @@ pub struct TrackerStreakAccount {
     pub last_streak_date: u64,
     pub longest_streak: u32,
     pub longest_streak_date: u64,
+    pub total_count: u32,
+    pub bump: u8,
 }
 
 #[account]
 pub struct TrackerStatsList {
     pub tracker_id: u32,
     pub stats: Vec<TrackerStatsAccount>,  // List of dates for which we have stats
+    // Cumulative count of distinct users who have ever tracked against this
+    // tracker, incremented once per user the first time their
+    // `TrackerStreakAccount` is created. Unlike `TrackerStatsAccount.unique_users`
+    // (a per-day count), this is the per-tracker total that
+    // `get_registry_snapshot` sums across the stats lists it is given.
+    pub total_unique_users: u32,
+    pub bump: u8,
+}
+
+// Maximum number of entries kept in each leaderboard mode
+pub const LEADERBOARD_TOP_N: usize = 50;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    pub value: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum LeaderboardMode {
+    LongestStreak,
+    TotalCount,
+}
+
+#[account]
+pub struct TrackerLeaderboard {
+    pub tracker_id: u32,
+    pub longest_streak_board: Vec<LeaderboardEntry>,
+    pub total_count_board: Vec<LeaderboardEntry>,
+    pub bump: u8,
+}
+
+impl TrackerLeaderboard {
+    pub const LEN: usize = 4 + // tracker_id
+        (4 + (32 + 4) * LEADERBOARD_TOP_N) + // longest_streak_board
+        (4 + (32 + 4) * LEADERBOARD_TOP_N) + // total_count_board
+        1; // bump
+}
+
+// Insert or update `user`'s entry in a leaderboard, keep it sorted
+// descending by value, and truncate to the top N entries.
+fn upsert_leaderboard_entry(board: &mut Vec<LeaderboardEntry>, user: Pubkey, value: u32, top_n: usize) {
+    if let Some(entry) = board.iter_mut().find(|e| e.user == user) {
+        entry.value = value;
+    } else {
+        board.push(LeaderboardEntry { user, value });
+    }
+
+    board.sort_by(|a, b| b.value.cmp(&a.value));
+    board.truncate(top_n);
+}
+
+// Shared by `add_tracking_data` and `backfill_tracking_data`: initialize the
+// streak/leaderboard accounts on first use, recompute the streak from the
+// full track history, and keep both leaderboards in sync with the result.
+#[allow(clippy::too_many_arguments)]
+fn sync_streak_and_leaderboard(
+    tracker_streak: &mut TrackerStreakAccount,
+    leaderboard: &mut TrackerLeaderboard,
+    tracker_stats_list: &mut TrackerStatsList,
+    user: Pubkey,
+    tracker_id: u32,
+    streak_bump: u8,
+    leaderboard_bump: u8,
+    tracks: &[Track],
+    fallback_date: u64,
+) {
+    if tracker_streak.user == Pubkey::default() {
+        tracker_streak.user = user;
+        tracker_streak.tracker_id = tracker_id;
+        tracker_streak.bump = streak_bump;
+        // This is the first time this user has tracked against this
+        // tracker, so count them towards the registry-wide total.
+        tracker_stats_list.total_unique_users += 1;
+    }
+
+    let (streak, longest_streak, longest_streak_date) = recompute_streak(tracks);
+    tracker_streak.streak = streak;
+    tracker_streak.longest_streak = longest_streak;
+    tracker_streak.longest_streak_date = longest_streak_date;
+    // `tracks` is sorted descending by date, so the first entry is the latest
+    tracker_streak.last_streak_date = tracks.first().map(|t| t.date).unwrap_or(fallback_date);
+    tracker_streak.total_count = tracks.iter().map(|t| t.count).sum();
+
+    if leaderboard.tracker_id == 0 {
+        leaderboard.tracker_id = tracker_id;
+        leaderboard.bump = leaderboard_bump;
+    }
+    upsert_leaderboard_entry(
+        &mut leaderboard.longest_streak_board,
+        user,
+        tracker_streak.longest_streak,
+        LEADERBOARD_TOP_N,
+    );
+    upsert_leaderboard_entry(
+        &mut leaderboard.total_count_board,
+        user,
+        tracker_streak.total_count,
+        LEADERBOARD_TOP_N,
+    );
 }
 
 
 impl TrackerStatsList {
+    // Starts empty; `realloc_account_for_growth` grows it by the exact
+    // amount needed as stats entries are pushed, so no fixed cap here.
     pub const LEN: usize = 4 + // tracker_id
-        4 + // stats_dates vector length
-        8 * 100; // space for 100 dates initially
+        4 + // stats vector length
+        4 + // total_unique_users
+        1; // bump
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -516,13 +1003,107 @@ pub enum TrackingError {
     InvalidTrackerId,
     #[msg("Tracking data already exists for this date")]
     TrackingDataAlreadyExists,
+    #[msg("Failed to compute account size")]
+    SerializationError,
+    #[msg("Tracker has been archived")]
+    TrackerArchived,
+    #[msg("Registry is paused")]
+    RegistryPaused,
+    #[msg("Tracker id counter overflowed")]
+    TrackerIdOverflow,
+}
+
+// Computes the exact borsh-serialized length of an account's contents, used
+// to size a `realloc` precisely instead of over-allocating rent up front.
+fn packed_len<T: AnchorSerialize>(value: &T) -> Result<usize> {
+    value
+        .try_to_vec()
+        .map(|bytes| bytes.len())
+        .map_err(|_| error!(TrackingError::SerializationError))
+}
+
+// Grows `account_info` to `8 + data_len` bytes (discriminator + data) if it
+// isn't already large enough, topping up rent from `payer` for the delta.
+fn realloc_account_for_growth<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    data_len: usize,
+) -> Result<()> {
+    let new_size = 8 + data_len;
+    if new_size <= account_info.data_len() {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?;
+    let new_minimum_balance = rent.minimum_balance(new_size);
+    let lamports_diff = new_minimum_balance.saturating_sub(account_info.lamports());
+    if lamports_diff > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account_info.clone(),
+                },
+            ),
+            lamports_diff,
+        )?;
+    }
+
+    account_info.realloc(new_size, false)?;
+    Ok(())
+}
+
+// Recomputes (current streak, longest streak, longest streak's end date)
+// from a user's full track history so out-of-order and backfilled entries
+// always converge on the correct values instead of drifting from an
+// incremental update. `tracks` may be in any order and may contain
+// duplicate dates. A gap of more than one day, or a day recorded with
+// `count == 0`, breaks the run; the current streak is whatever run is
+// still active as of the latest date.
+fn recompute_streak(tracks: &[Track]) -> (u32, u32, u64) {
+    let mut sorted: Vec<&Track> = tracks.iter().collect();
+    sorted.sort_by(|a, b| a.date.cmp(&b.date));
+
+    let one_day = 86400;
+    let mut run: u32 = 0;
+    let mut longest_streak: u32 = 0;
+    let mut longest_streak_date: u64 = 0;
+    let mut prev_date: Option<u64> = None;
+
+    for track in sorted {
+        match prev_date {
+            Some(prev) if track.date == prev => {
+                // Duplicate date: doesn't extend or break the run
+            }
+            Some(prev) if track.date == prev + one_day => {
+                run = if track.count > 0 { run + 1 } else { 0 };
+            }
+            _ => {
+                // First entry, or a gap of more than one day
+                run = if track.count > 0 { 1 } else { 0 };
+            }
+        }
+
+        if run > longest_streak {
+            longest_streak = run;
+            longest_streak_date = track.date;
+        }
+
+        prev_date = Some(track.date);
+    }
+
+    (run, longest_streak, longest_streak_date)
 }
 
 impl TrackingData {
+    // Starts empty; `realloc_account_for_growth` grows it by the exact
+    // amount needed as tracks are pushed, so no fixed cap here.
     pub const LEN: usize = 32 + // user
         4 + // tracker_id
         4 + // tracks vector length
-        (8 + 4) * 100; // space for 100 tracks initially
+        1; // bump
 }
 
 impl Tracker {
@@ -530,11 +1111,13 @@ impl Tracker {
         4 + // title length
         32 + // title (max 32 chars)
         4 + // description length
-        100; // description (max 100 chars)
+        100 + // description (max 100 chars)
+        1 + // is_archived
+        1; // bump
 }
 
 impl TrackerStatsAccount {
-    pub const LEN: usize = 4 + 8 + 4 + 4; // tracker_id (u32) + date (u64) + total_count (u32) + unique_users (u32)
+    pub const LEN: usize = 4 + 8 + 4 + 4 + 1; // tracker_id (u32) + date (u64) + total_count (u32) + unique_users (u32) + bump
 }
 
 impl TrackerStreakAccount {
@@ -543,5 +1126,90 @@ impl TrackerStreakAccount {
         4 + // streak (u32)
         8 + // last_streak_date (u64)
         4 + // longest_streak (u32)
-        8; // longest_streak_date (u64)
+        8 + // longest_streak_date (u64)
+        4 + // total_count (u32)
+        1; // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `TrackingData.tracks` and `TrackerStatsList.stats` are both grown
+    // on-demand via `realloc_account_for_growth`, which sizes the account
+    // from `packed_len`. These tests push both vectors well past 100
+    // entries and check that the borsh-computed length keeps growing
+    // correctly and that the account still round-trips through
+    // `try_to_vec`/`try_from_slice`.
+
+    #[test]
+    fn tracking_data_grows_and_round_trips_past_100_tracks() {
+        let mut tracking_data = TrackingData {
+            user: Pubkey::new_unique(),
+            tracker_id: 1,
+            tracks: Vec::new(),
+            bump: 255,
+        };
+
+        let mut previous_len = packed_len(&tracking_data).unwrap();
+        for i in 0..150u64 {
+            tracking_data.tracks.push(Track {
+                date: i * 86400,
+                count: i as u32,
+            });
+
+            let new_len = packed_len(&tracking_data).unwrap();
+            assert!(new_len > previous_len);
+            previous_len = new_len;
+        }
+
+        assert_eq!(tracking_data.tracks.len(), 150);
+
+        let bytes = tracking_data.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), previous_len);
+
+        let deserialized = TrackingData::try_from_slice(&bytes).unwrap();
+        assert_eq!(deserialized.user, tracking_data.user);
+        assert_eq!(deserialized.tracks.len(), 150);
+        for (original, round_tripped) in tracking_data.tracks.iter().zip(deserialized.tracks.iter()) {
+            assert_eq!(original.date, round_tripped.date);
+            assert_eq!(original.count, round_tripped.count);
+        }
+    }
+
+    #[test]
+    fn tracker_stats_list_grows_and_round_trips_past_100_entries() {
+        let mut stats_list = TrackerStatsList {
+            tracker_id: 1,
+            stats: Vec::new(),
+            total_unique_users: 0,
+            bump: 255,
+        };
+
+        let mut previous_len = packed_len(&stats_list).unwrap();
+        for i in 0..120u64 {
+            stats_list.stats.push(TrackerStatsAccount {
+                tracker_id: 1,
+                date: i * 86400,
+                total_count: i as u32,
+                unique_users: 1,
+                bump: 254,
+            });
+            stats_list.total_unique_users += 1;
+
+            let new_len = packed_len(&stats_list).unwrap();
+            assert!(new_len > previous_len);
+            previous_len = new_len;
+        }
+
+        assert_eq!(stats_list.stats.len(), 120);
+        assert_eq!(stats_list.total_unique_users, 120);
+
+        let bytes = stats_list.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), previous_len);
+
+        let deserialized = TrackerStatsList::try_from_slice(&bytes).unwrap();
+        assert_eq!(deserialized.stats.len(), 120);
+        assert_eq!(deserialized.total_unique_users, 120);
+    }
 }